@@ -36,4 +36,14 @@ pub enum ContractError {
     SettlementExpired = 11,
     /// Settlement has already been executed for this remittance
     DuplicateSettlement = 12,
+    /// Refund with the specified ID does not exist
+    RefundNotFound = 13,
+    /// Operation not allowed for refund in current status
+    InvalidRefundStatus = 14,
+    /// Refund has already been claimed by the sender
+    RefundAlreadyClaimed = 15,
+    /// Idempotency key was already used to create a remittance with different parameters
+    DuplicateIdempotencyKey = 16,
+    /// Payout confirmation attempted past the configured `max_attempts` ceiling
+    RetryLimitExceeded = 17,
 }
@@ -4,10 +4,13 @@
 //! contract operations. Events include schema versioning and ledger metadata
 //! for comprehensive audit trails.
 
-use soroban_sdk::{symbol_short, Address, Env};
+use soroban_sdk::{symbol_short, Address, BytesN, Env};
 
 /// Schema version for event structure compatibility
-const SCHEMA_VERSION: u32 = 1;
+///
+/// Bumped to 2 when `emit_remittance_created` gained the
+/// `idempotency_key` field.
+const SCHEMA_VERSION: u32 = 2;
 
 // ── Remittance Events ──────────────────────────────────────────────
 
@@ -21,6 +24,7 @@ const SCHEMA_VERSION: u32 = 1;
 /// * `agent` - Address of the assigned agent
 /// * `amount` - Total remittance amount
 /// * `fee` - Platform fee deducted
+/// * `idempotency_key` - Client-supplied key used to deduplicate retries
 pub fn emit_remittance_created(
     env: &Env,
     remittance_id: u64,
@@ -28,6 +32,7 @@ pub fn emit_remittance_created(
     agent: Address,
     amount: i128,
     fee: i128,
+    idempotency_key: BytesN<32>,
 ) {
     env.events().publish(
         (symbol_short!("remit"), symbol_short!("created")),
@@ -40,6 +45,7 @@ pub fn emit_remittance_created(
             agent,
             amount,
             fee,
+            idempotency_key,
         ),
     );
 }
@@ -98,6 +104,127 @@ pub fn emit_remittance_cancelled(
     );
 }
 
+/// Emits an event when a remittance expires and is reclaimed by the sender.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `remittance_id` - ID of the expired remittance
+/// * `sender` - Address of the sender who reclaimed the funds
+/// * `amount` - Reclaimed amount
+pub fn emit_remittance_expired(env: &Env, remittance_id: u64, sender: Address, amount: i128) {
+    env.events().publish(
+        (symbol_short!("remit"), symbol_short!("expired")),
+        (
+            SCHEMA_VERSION,
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            remittance_id,
+            sender,
+            amount,
+        ),
+    );
+}
+
+/// Emits an event when a remittance's failed payout attempts reach `max_attempts`.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `remittance_id` - ID of the exhausted remittance
+/// * `attempts` - Number of failed confirmation attempts recorded
+pub fn emit_remittance_exhausted(env: &Env, remittance_id: u64, attempts: u32) {
+    env.events().publish(
+        (symbol_short!("remit"), symbol_short!("exhausted")),
+        (
+            SCHEMA_VERSION,
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            remittance_id,
+            attempts,
+        ),
+    );
+}
+
+// ── Refund Events ──────────────────────────────────────────────────
+
+/// Emits an event when an agent creates a standing refund request.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `refund_id` - Unique ID of the created refund
+/// * `remittance_id` - ID of the remittance the refund was raised against
+/// * `agent` - Address of the agent who created the refund
+/// * `sender` - Address of the sender who may claim the refund
+/// * `amount` - Amount available to be claimed
+pub fn emit_refund_created(
+    env: &Env,
+    refund_id: u64,
+    remittance_id: u64,
+    agent: Address,
+    sender: Address,
+    amount: i128,
+) {
+    env.events().publish(
+        (symbol_short!("refund"), symbol_short!("created")),
+        (
+            SCHEMA_VERSION,
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            refund_id,
+            remittance_id,
+            agent,
+            sender,
+            amount,
+        ),
+    );
+}
+
+/// Emits an event when a sender claims a refund.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `refund_id` - ID of the claimed refund
+/// * `sender` - Address of the sender who claimed the refund
+/// * `amount` - Claimed amount
+pub fn emit_refund_claimed(env: &Env, refund_id: u64, sender: Address, amount: i128) {
+    env.events().publish(
+        (symbol_short!("refund"), symbol_short!("claimed")),
+        (
+            SCHEMA_VERSION,
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            refund_id,
+            sender,
+            amount,
+        ),
+    );
+}
+
+/// Emits an event when a refund expires unclaimed.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `refund_id` - ID of the expired refund
+/// * `agent` - Address of the agent who created the refund
+/// * `amount` - Amount that was never claimed
+pub fn emit_refund_expired(env: &Env, refund_id: u64, agent: Address, amount: i128) {
+    env.events().publish(
+        (symbol_short!("refund"), symbol_short!("expired")),
+        (
+            SCHEMA_VERSION,
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            refund_id,
+            agent,
+            amount,
+        ),
+    );
+}
+
 // ── Agent Events ───────────────────────────────────────────────────
 
 /// Emits an event when a new agent is registered.
@@ -3,7 +3,7 @@
 //! This module defines the core data structures used throughout the contract,
 //! including remittance records and status enums.
 
-use soroban_sdk::{contracttype, Address};
+use soroban_sdk::{contracttype, Address, BytesN};
 
 /// Status of a remittance transaction.
 ///
@@ -11,6 +11,7 @@ use soroban_sdk::{contracttype, Address};
 /// - `Pending`: Initial state after creation, awaiting agent confirmation
 /// - `Completed`: Agent has confirmed payout and received funds
 /// - `Cancelled`: Sender has cancelled and received refund
+/// - `Expired`: Settlement window lapsed and the sender reclaimed the funds
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum RemittanceStatus {
@@ -20,6 +21,8 @@ pub enum RemittanceStatus {
     Completed,
     /// Remittance has been cancelled and refunded to sender
     Cancelled,
+    /// Remittance's expiry lapsed before settlement and was reclaimed by the sender
+    Expired,
 }
 
 /// A remittance transaction record.
@@ -43,4 +46,49 @@ pub struct Remittance {
     pub status: RemittanceStatus,
     /// Optional expiry timestamp (seconds since epoch) for settlement
     pub expiry: Option<u64>,
+    /// Client-supplied key used to deduplicate retried `create` calls
+    pub idempotency_key: BytesN<32>,
+    /// Number of failed payout confirmation attempts recorded so far
+    pub attempts: u32,
+}
+
+/// Status of a refund request.
+///
+/// Mirrors the lifecycle of a BOLT 12 style refund: an agent stands up
+/// a standing offer for money, the original sender later claims it, and
+/// an unclaimed offer can lapse once its expiry passes.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum RefundStatus {
+    /// Refund has been created by the agent and awaits the sender's claim
+    Requested,
+    /// Sender has claimed the refund and received the funds
+    Claimed,
+    /// Refund expired before the sender claimed it
+    Expired,
+}
+
+/// A standing refund request created by an agent who took custody of
+/// funds but could not complete the payout.
+///
+/// The contract persists this record at creation time, and the claim
+/// path validates an incoming claim against the stored record rather
+/// than trusting the caller's parameters.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Refund {
+    /// Unique identifier for this refund
+    pub id: u64,
+    /// ID of the remittance this refund was raised against
+    pub remittance_id: u64,
+    /// Address of the agent who created the refund
+    pub agent: Address,
+    /// Address of the original sender who may claim the refund
+    pub sender: Address,
+    /// Amount available to be claimed
+    pub amount: i128,
+    /// Current status of the refund
+    pub status: RefundStatus,
+    /// Optional expiry timestamp (seconds since epoch) for the claim
+    pub expiry: Option<u64>,
 }